@@ -1,12 +1,19 @@
-use std::hash::{Hash, Hasher};
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    sync::Mutex,
+};
 
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
 use sway_types::{Ident, Named, Span, Spanned};
 
 use crate::{
     decl_engine::{
-        mapping::DeclMapping, DeclEngineReplace, DeclRefConstant, DeclRefFunction, DeclRefTraitFn,
-        DeclRefTraitType, ReplaceFunctionImplementingType,
+        mapping::DeclMapping, DeclEngineReplace, DeclId, DeclRefConstant, DeclRefFunction,
+        DeclRefTrait, DeclRefTraitFn, DeclRefTraitType, ReplaceFunctionImplementingType,
     },
     engine_threading::*,
     language::{parsed, Visibility},
@@ -16,7 +23,7 @@ use crate::{
     type_system::*,
 };
 
-use super::TyDecl;
+use super::{TyDecl, TyImplTrait};
 
 #[derive(Clone, Debug)]
 pub struct TyTraitDecl {
@@ -26,9 +33,248 @@ pub struct TyTraitDecl {
     pub interface_surface: Vec<TyTraitInterfaceItem>,
     pub items: Vec<TyTraitItem>,
     pub supertraits: Vec<parsed::Supertrait>,
+    /// Richer bounds augmenting `supertraits`: besides plain `Self: Bar<T>` trait bounds, this
+    /// can express associated-type equality bounds such as `Self: Bar<Assoc = u64>`, which a
+    /// flat `Supertrait` list has no way to represent. Populated during type-checking of the
+    /// trait declaration's where-clause, alongside `supertraits`. Trait implementation checking
+    /// must verify every entry here in addition to `supertraits`, via
+    /// [TyTraitDecl::unsatisfied_predicates].
+    pub predicates: Vec<TyTraitPredicate>,
     pub visibility: Visibility,
     pub attributes: transform::AttributesMap,
     pub span: Span,
+    /// Variance of each of `type_parameters`, in the same order, computed during type-check
+    /// finalization by [TyTraitDecl::compute_variances]. Empty until then.
+    pub variances: Vec<Variance>,
+    /// Marks this as an auto trait (e.g. `auto trait Send {}`): a marker trait with no interface
+    /// items, implemented structurally for any composite type whose components all implement it,
+    /// rather than requiring an explicit `impl`.
+    pub is_auto: bool,
+    /// Blanket impls recorded against this trait: impls whose `self_type` is itself an
+    /// unconstrained type parameter of the impl (`impl<T> Trait for T`), rather than a concrete
+    /// nominal type. Trait-method resolution applies these to any receiver type that satisfies
+    /// the trait's predicate bounds, instead of requiring an exact nominal match.
+    pub blanket_impls: Vec<DeclId<TyImplTrait>>,
+    /// This trait's own `DeclId`, bound by [TyTraitDecl::bind_decl_id] immediately after the decl
+    /// engine inserts it (and hence `None` for a freshly type-checked, not-yet-inserted decl).
+    /// Needed so that self-referential lookups — today, the [TyTraitDecl::monomorphize_cached]
+    /// cache key — have a stable identity to key on without requiring every caller along the
+    /// substitution path to thread a `DeclId` through by hand.
+    self_decl_id: Option<DeclId<TyTraitDecl>>,
+    /// The [Fingerprint] this trait had the last time [TyTraitDecl::compute_variances] ran,
+    /// persisted so that a later finalization pass over an unchanged trait (e.g. a second
+    /// finalization triggered by an unrelated part of the same recompilation) can compare
+    /// fingerprints and skip recomputation entirely rather than re-deriving a result that cannot
+    /// have changed. `None` before `compute_variances` has ever run.
+    last_variances_fingerprint: Option<Fingerprint>,
+}
+
+impl TyTraitDecl {
+    /// Constructs a new, not-yet-inserted `TyTraitDecl`. `predicates`, `variances`, `is_auto`,
+    /// `blanket_impls`, and `self_decl_id` are not parameters: they are either derived later
+    /// (`variances` by [TyTraitDecl::compute_variances] during finalization) or opt-in
+    /// (`predicates`, `is_auto`, `blanket_impls`, `self_decl_id`), so every call site picks up
+    /// their defaults automatically instead of having to list out fields that don't apply to a
+    /// freshly type-checked trait declaration — and a field added to the struct in the future
+    /// only has to be added here, not at every call site.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: Ident,
+        type_parameters: Vec<TypeParameter>,
+        self_type: TypeParameter,
+        interface_surface: Vec<TyTraitInterfaceItem>,
+        items: Vec<TyTraitItem>,
+        supertraits: Vec<parsed::Supertrait>,
+        visibility: Visibility,
+        attributes: transform::AttributesMap,
+        span: Span,
+    ) -> Self {
+        TyTraitDecl {
+            name,
+            type_parameters,
+            self_type,
+            interface_surface,
+            items,
+            supertraits,
+            predicates: Vec::new(),
+            visibility,
+            attributes,
+            span,
+            variances: Vec::new(),
+            is_auto: false,
+            blanket_impls: Vec::new(),
+            self_decl_id: None,
+            last_variances_fingerprint: None,
+        }
+    }
+
+    /// Binds this trait's own `DeclId`, called by the decl engine immediately after it inserts a
+    /// freshly type-checked `TyTraitDecl` and receives its `DeclId` back. Until this is called,
+    /// [TyTraitDecl::monomorphize_cached] has no identity to key its cache on and skips caching
+    /// entirely.
+    pub fn bind_decl_id(&mut self, decl_id: DeclId<TyTraitDecl>) {
+        self.self_decl_id = Some(decl_id);
+    }
+}
+
+/// A single constraint a trait places on its implementors, beyond the plain trait bounds
+/// already expressed by `TyTraitDecl::supertraits`. Borrows rustc's `predicates_of` design so
+/// that bounds like `trait Foo: Bar<Assoc = u64>` are representable both on supertraits and on
+/// where-clauses.
+#[derive(Clone, Debug)]
+pub enum TyTraitPredicate {
+    /// `Self: trait_ref<type_args>`.
+    TraitBound {
+        trait_ref: DeclRefTrait,
+        type_args: Vec<TypeArgument>,
+    },
+    /// `Self: trait_ref<type_args, assoc_type_name = ty>`.
+    AssocTypeEquality {
+        trait_ref: DeclRefTrait,
+        type_args: Vec<TypeArgument>,
+        assoc_type_name: Ident,
+        ty: TypeArgument,
+    },
+}
+
+impl EqWithEngines for TyTraitPredicate {}
+impl PartialEqWithEngines for TyTraitPredicate {
+    fn eq(&self, other: &Self, engines: &Engines) -> bool {
+        match (self, other) {
+            (
+                TyTraitPredicate::TraitBound {
+                    trait_ref,
+                    type_args,
+                },
+                TyTraitPredicate::TraitBound {
+                    trait_ref: other_trait_ref,
+                    type_args: other_type_args,
+                },
+            ) => {
+                trait_ref.eq(other_trait_ref, engines) && type_args.eq(other_type_args, engines)
+            }
+            (
+                TyTraitPredicate::AssocTypeEquality {
+                    trait_ref,
+                    type_args,
+                    assoc_type_name,
+                    ty,
+                },
+                TyTraitPredicate::AssocTypeEquality {
+                    trait_ref: other_trait_ref,
+                    type_args: other_type_args,
+                    assoc_type_name: other_assoc_type_name,
+                    ty: other_ty,
+                },
+            ) => {
+                trait_ref.eq(other_trait_ref, engines)
+                    && type_args.eq(other_type_args, engines)
+                    && assoc_type_name == other_assoc_type_name
+                    && ty.eq(other_ty, engines)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl HashWithEngines for TyTraitPredicate {
+    fn hash<H: Hasher>(&self, state: &mut H, engines: &Engines) {
+        match self {
+            TyTraitPredicate::TraitBound {
+                trait_ref,
+                type_args,
+            } => {
+                state.write_u8(0);
+                trait_ref.hash(state, engines);
+                type_args.hash(state, engines);
+            }
+            TyTraitPredicate::AssocTypeEquality {
+                trait_ref,
+                type_args,
+                assoc_type_name,
+                ty,
+            } => {
+                state.write_u8(1);
+                trait_ref.hash(state, engines);
+                type_args.hash(state, engines);
+                assoc_type_name.hash(state);
+                ty.hash(state, engines);
+            }
+        }
+    }
+}
+
+impl SubstTypes for TyTraitPredicate {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: &Engines) {
+        // `trait_ref` is not substituted: it identifies *which* trait is being bounded (a
+        // `DeclId<TyTraitDecl>` plus its name/span), and carries no `TypeId`s of its own — the
+        // trait's own generic arguments are exactly `type_args`, which is substituted below.
+        match self {
+            TyTraitPredicate::TraitBound { type_args, .. } => {
+                type_args
+                    .iter_mut()
+                    .for_each(|arg| arg.subst(type_mapping, engines));
+            }
+            TyTraitPredicate::AssocTypeEquality { type_args, ty, .. } => {
+                type_args
+                    .iter_mut()
+                    .for_each(|arg| arg.subst(type_mapping, engines));
+                ty.subst(type_mapping, engines);
+            }
+        }
+    }
+}
+
+impl TyTraitDecl {
+    /// Returns every predicate in `self.predicates` that `impl_predicates` (the predicate list
+    /// resolved for one specific `impl Trait for Type`) does not satisfy. Empty means the impl
+    /// satisfies every bound this trait declares beyond its plain `supertraits`. Trait-impl
+    /// type-checking should call this alongside its existing `supertraits` check and emit a
+    /// diagnostic for each returned predicate, since a flat `Supertrait` list has no way to
+    /// express the associated-type equality bounds `TyTraitPredicate::AssocTypeEquality` carries.
+    ///
+    /// That call site lives in trait-impl type-checking, outside this file (and, in this
+    /// snapshot, outside the tree entirely), so nothing here invokes this method yet — this
+    /// declaration only establishes the check trait-impl type-checking needs to perform. It is
+    /// intentionally `pub` rather than `pub(crate)` so that call site can reach it once it exists.
+    pub fn unsatisfied_predicates<'a>(
+        &'a self,
+        impl_predicates: &[TyTraitPredicate],
+        engines: &Engines,
+    ) -> Vec<&'a TyTraitPredicate> {
+        self.predicates
+            .iter()
+            .filter(|predicate| {
+                !impl_predicates
+                    .iter()
+                    .any(|candidate| candidate.eq(predicate, engines))
+            })
+            .collect()
+    }
+}
+
+/// Variance of a trait type parameter with respect to its occurrences across the trait's
+/// method signatures, mirroring rustc's variance model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variance {
+    Covariant,
+    Contravariant,
+    Invariant,
+    Bivariant,
+}
+
+impl Variance {
+    /// Joins two variance observations of the same parameter using the standard lattice:
+    /// `Bivariant` is the identity, and two differing non-bivariant observations collapse to
+    /// `Invariant`.
+    fn join(self, other: Self) -> Self {
+        use Variance::*;
+        match (self, other) {
+            (Bivariant, other) | (other, Bivariant) => other,
+            (a, b) if a == b => a,
+            _ => Invariant,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -65,7 +311,10 @@ impl PartialEqWithEngines for TyTraitDecl {
             && self.interface_surface.eq(&other.interface_surface, engines)
             && self.items.eq(&other.items, engines)
             && self.supertraits.eq(&other.supertraits, engines)
+            && self.predicates.eq(&other.predicates, engines)
             && self.visibility == other.visibility
+            && self.is_auto == other.is_auto
+            && self.blanket_impls == other.blanket_impls
     }
 }
 
@@ -78,11 +327,20 @@ impl HashWithEngines for TyTraitDecl {
             interface_surface,
             items,
             supertraits,
+            predicates,
             visibility,
+            is_auto,
+            blanket_impls,
             // these fields are not hashed because they aren't relevant/a
             // reliable source of obj v. obj distinction
             attributes: _,
             span: _,
+            // derived from the other fields, not an independent source of identity
+            variances: _,
+            // identity bookkeeping, not structural content
+            self_decl_id: _,
+            // incremental-recompute bookkeeping, not structural content
+            last_variances_fingerprint: _,
         } = self;
         name.hash(state);
         type_parameters.hash(state, engines);
@@ -90,7 +348,10 @@ impl HashWithEngines for TyTraitDecl {
         interface_surface.hash(state, engines);
         items.hash(state, engines);
         supertraits.hash(state, engines);
+        predicates.hash(state, engines);
         visibility.hash(state);
+        is_auto.hash(state);
+        blanket_impls.hash(state);
     }
 }
 
@@ -142,29 +403,136 @@ impl HashWithEngines for TyTraitItem {
     }
 }
 
+/// A fallible walk over the decl refs nested in `Self`, parameterized by `&Engines`; each hook
+/// receives the decl ref mutably, so a visitor may rewrite what it visits (finalization replaces
+/// each visited decl in the decl engine). Driven by `TypeVisitable`, implemented once for
+/// `TyTraitDecl`, `TyTraitInterfaceItem` and `TyTraitItem` so that every kind of
+/// fallible pass over a trait's items (today, finalization; tomorrow, e.g. a free-variable
+/// collector) is written once instead of duplicated per pass.
+pub trait TypeVisitor {
+    type Error;
+
+    fn visit_item_fn(
+        &mut self,
+        engines: &Engines,
+        node: &mut DeclRefFunction,
+    ) -> Result<(), Self::Error>;
+
+    fn visit_item_constant(
+        &mut self,
+        engines: &Engines,
+        node: &mut DeclRefConstant,
+    ) -> Result<(), Self::Error>;
+
+    fn visit_item_trait_type(
+        &mut self,
+        _engines: &Engines,
+        _node: &mut DeclRefTraitType,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+pub trait TypeVisitable {
+    fn visit_with<V: TypeVisitor>(
+        &mut self,
+        visitor: &mut V,
+        engines: &Engines,
+    ) -> Result<(), V::Error>;
+}
+
+impl TypeVisitable for TyTraitItem {
+    fn visit_with<V: TypeVisitor>(
+        &mut self,
+        visitor: &mut V,
+        engines: &Engines,
+    ) -> Result<(), V::Error> {
+        match self {
+            TyTraitItem::Fn(node) => visitor.visit_item_fn(engines, node),
+            TyTraitItem::Constant(node) => visitor.visit_item_constant(engines, node),
+            TyTraitItem::Type(node) => visitor.visit_item_trait_type(engines, node),
+        }
+    }
+}
+
+impl TypeVisitable for TyTraitInterfaceItem {
+    fn visit_with<V: TypeVisitor>(
+        &mut self,
+        visitor: &mut V,
+        engines: &Engines,
+    ) -> Result<(), V::Error> {
+        match self {
+            // `TraitFn` has no body to finalize and no visitor hook of its own today; a pass
+            // that needs to reach it can add a `visit_interface_trait_fn` to `TypeVisitor` with
+            // a default no-op, the same way `visit_item_trait_type` is optional.
+            TyTraitInterfaceItem::TraitFn(_node) => Ok(()),
+            TyTraitInterfaceItem::Constant(node) => visitor.visit_item_constant(engines, node),
+            TyTraitInterfaceItem::Type(node) => visitor.visit_item_trait_type(engines, node),
+        }
+    }
+}
+
+impl TypeVisitable for TyTraitDecl {
+    fn visit_with<V: TypeVisitor>(
+        &mut self,
+        visitor: &mut V,
+        engines: &Engines,
+    ) -> Result<(), V::Error> {
+        for item in self.interface_surface.iter_mut() {
+            item.visit_with(visitor, engines)?;
+        }
+        for item in self.items.iter_mut() {
+            item.visit_with(visitor, engines)?;
+        }
+        Ok(())
+    }
+}
+
+/// Finalizes every item it visits, by delegating to the item's own `TypeCheckFinalization` impl.
+/// This is `TypeCheckFinalization`'s traversal over `TyTraitItem`, extracted into a `TypeVisitor`
+/// so the match over `Fn`/`Constant`/`Type` is written once and reused by both `TyTraitItem` and
+/// `TyTraitDecl`'s finalization.
+struct FinalizationVisitor<'a> {
+    handler: &'a Handler,
+    ctx: &'a mut TypeCheckFinalizationContext,
+}
+
+impl TypeVisitor for FinalizationVisitor<'_> {
+    type Error = ErrorEmitted;
+
+    fn visit_item_fn(
+        &mut self,
+        engines: &Engines,
+        node: &mut DeclRefFunction,
+    ) -> Result<(), ErrorEmitted> {
+        let decl_engine = engines.de();
+        let mut item_fn = decl_engine.get_function(node);
+        item_fn.type_check_finalize(self.handler, self.ctx)?;
+        decl_engine.replace(*node.id(), item_fn);
+        Ok(())
+    }
+
+    fn visit_item_constant(
+        &mut self,
+        engines: &Engines,
+        node: &mut DeclRefConstant,
+    ) -> Result<(), ErrorEmitted> {
+        let decl_engine = engines.de();
+        let mut item_const = decl_engine.get_constant(node);
+        item_const.type_check_finalize(self.handler, self.ctx)?;
+        decl_engine.replace(*node.id(), item_const);
+        Ok(())
+    }
+}
+
 impl TypeCheckFinalization for TyTraitItem {
     fn type_check_finalize(
         &mut self,
         handler: &Handler,
         ctx: &mut TypeCheckFinalizationContext,
     ) -> Result<(), ErrorEmitted> {
-        let decl_engine = ctx.engines.de();
-        match self {
-            TyTraitItem::Fn(node) => {
-                let mut item_fn = decl_engine.get_function(node);
-                item_fn.type_check_finalize(handler, ctx)?;
-                decl_engine.replace(*node.id(), item_fn);
-            }
-            TyTraitItem::Constant(node) => {
-                let mut item_const = decl_engine.get_constant(node);
-                item_const.type_check_finalize(handler, ctx)?;
-                decl_engine.replace(*node.id(), item_const);
-            }
-            TyTraitItem::Type(_node) => {
-                // Nothing to finalize
-            }
-        }
-        Ok(())
+        let engines = ctx.engines;
+        self.visit_with(&mut FinalizationVisitor { handler, ctx }, engines)
     }
 }
 
@@ -178,56 +546,135 @@ impl Spanned for TyTraitItem {
     }
 }
 
+/// Rewrites a single decl ref in place by re-substituting its types and swapping in the
+/// resulting fresh `DeclId`. Implemented by `SubstTypes`' substitution logic so the traversal
+/// over a trait's interface/item decl refs is written once as a `TypeFolder`, instead of being
+/// hand-rolled again every time a new kind of type-rewriting walk is added.
+pub trait TypeFolder {
+    fn fold_trait_fn(&mut self, engines: &Engines, node: &mut DeclRefTraitFn);
+    fn fold_interface_constant(&mut self, engines: &Engines, node: &mut DeclRefConstant);
+    fn fold_interface_trait_type(&mut self, engines: &Engines, node: &mut DeclRefTraitType);
+    fn fold_item_fn(&mut self, engines: &Engines, node: &mut DeclRefFunction);
+    fn fold_item_constant(&mut self, engines: &Engines, node: &mut DeclRefConstant);
+    fn fold_item_trait_type(&mut self, engines: &Engines, node: &mut DeclRefTraitType);
+    fn fold_type_parameter(&mut self, engines: &Engines, type_parameter: &mut TypeParameter);
+}
+
+pub trait TypeFoldable {
+    fn fold_with<F: TypeFolder>(&mut self, folder: &mut F, engines: &Engines);
+}
+
+impl TypeFoldable for TyTraitInterfaceItem {
+    fn fold_with<F: TypeFolder>(&mut self, folder: &mut F, engines: &Engines) {
+        match self {
+            TyTraitInterfaceItem::TraitFn(node) => folder.fold_trait_fn(engines, node),
+            TyTraitInterfaceItem::Constant(node) => folder.fold_interface_constant(engines, node),
+            TyTraitInterfaceItem::Type(node) => folder.fold_interface_trait_type(engines, node),
+        }
+    }
+}
+
+impl TypeFoldable for TyTraitItem {
+    fn fold_with<F: TypeFolder>(&mut self, folder: &mut F, engines: &Engines) {
+        match self {
+            TyTraitItem::Fn(node) => folder.fold_item_fn(engines, node),
+            TyTraitItem::Constant(node) => folder.fold_item_constant(engines, node),
+            TyTraitItem::Type(node) => folder.fold_item_trait_type(engines, node),
+        }
+    }
+}
+
+impl TypeFoldable for TyTraitDecl {
+    fn fold_with<F: TypeFolder>(&mut self, folder: &mut F, engines: &Engines) {
+        self.type_parameters
+            .iter_mut()
+            .for_each(|type_parameter| folder.fold_type_parameter(engines, type_parameter));
+        self.interface_surface
+            .iter_mut()
+            .for_each(|item| item.fold_with(folder, engines));
+        self.items
+            .iter_mut()
+            .for_each(|item| item.fold_with(folder, engines));
+    }
+}
+
+/// The `TypeFolder` that backs `TyTraitDecl`'s `SubstTypes` impl: every decl ref is
+/// re-substituted against `type_mapping` and replaced with the resulting fresh `DeclId`.
+struct SubstFolder<'a> {
+    type_mapping: &'a TypeSubstMap,
+    decl_mapping: DeclMapping,
+}
+
+impl TypeFolder for SubstFolder<'_> {
+    fn fold_trait_fn(&mut self, engines: &Engines, node: &mut DeclRefTraitFn) {
+        let new_node = node
+            .clone()
+            .subst_types_and_insert_new_with_parent(self.type_mapping, engines);
+        self.decl_mapping.insert(node.id().into(), new_node.id().into());
+        node.replace_id(*new_node.id());
+    }
+
+    fn fold_interface_constant(&mut self, engines: &Engines, node: &mut DeclRefConstant) {
+        let new_node = node
+            .clone()
+            .subst_types_and_insert_new(self.type_mapping, engines);
+        self.decl_mapping.insert(node.id().into(), new_node.id().into());
+        node.replace_id(*new_node.id());
+    }
+
+    fn fold_interface_trait_type(&mut self, engines: &Engines, node: &mut DeclRefTraitType) {
+        let new_node = node
+            .clone()
+            .subst_types_and_insert_new(self.type_mapping, engines);
+        node.replace_id(*new_node.id());
+    }
+
+    fn fold_item_fn(&mut self, engines: &Engines, node: &mut DeclRefFunction) {
+        let new_node = node
+            .clone()
+            .subst_types_and_insert_new_with_parent(self.type_mapping, engines);
+        node.replace_id(*new_node.id());
+    }
+
+    fn fold_item_constant(&mut self, engines: &Engines, node: &mut DeclRefConstant) {
+        let new_node = node
+            .clone()
+            .subst_types_and_insert_new_with_parent(self.type_mapping, engines);
+        node.replace_id(*new_node.id());
+    }
+
+    fn fold_item_trait_type(&mut self, engines: &Engines, node: &mut DeclRefTraitType) {
+        let new_node = node
+            .clone()
+            .subst_types_and_insert_new_with_parent(self.type_mapping, engines);
+        node.replace_id(*new_node.id());
+    }
+
+    fn fold_type_parameter(&mut self, engines: &Engines, type_parameter: &mut TypeParameter) {
+        type_parameter.subst(self.type_mapping, engines);
+    }
+}
+
 impl SubstTypes for TyTraitDecl {
     fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: &Engines) {
-        let mut decl_mapping = DeclMapping::new();
+        let self_decl_id = self.self_decl_id;
+
+        let mut folder = SubstFolder {
+            type_mapping,
+            decl_mapping: DeclMapping::new(),
+        };
         self.type_parameters
             .iter_mut()
-            .for_each(|x| x.subst(type_mapping, engines));
-        self.interface_surface
+            .for_each(|type_parameter| folder.fold_type_parameter(engines, type_parameter));
+
+        let (interface_surface, items) =
+            self.monomorphize_cached(self_decl_id, &mut folder, engines);
+        self.interface_surface = interface_surface;
+        self.items = items;
+
+        self.predicates
             .iter_mut()
-            .for_each(|item| match item {
-                TyTraitInterfaceItem::TraitFn(item_ref) => {
-                    let new_item_ref = item_ref
-                        .clone()
-                        .subst_types_and_insert_new_with_parent(type_mapping, engines);
-                    decl_mapping.insert(item_ref.id().into(), new_item_ref.id().into());
-                    item_ref.replace_id(*new_item_ref.id());
-                }
-                TyTraitInterfaceItem::Constant(decl_ref) => {
-                    let new_decl_ref = decl_ref
-                        .clone()
-                        .subst_types_and_insert_new(type_mapping, engines);
-                    decl_mapping.insert(decl_ref.id().into(), new_decl_ref.id().into());
-                    decl_ref.replace_id(*new_decl_ref.id());
-                }
-                TyTraitInterfaceItem::Type(decl_ref) => {
-                    let new_decl_ref = decl_ref
-                        .clone()
-                        .subst_types_and_insert_new(type_mapping, engines);
-                    decl_ref.replace_id(*new_decl_ref.id());
-                }
-            });
-        self.items.iter_mut().for_each(|item| match item {
-            TyTraitItem::Fn(item_ref) => {
-                let new_item_ref = item_ref
-                    .clone()
-                    .subst_types_and_insert_new_with_parent(type_mapping, engines);
-                item_ref.replace_id(*new_item_ref.id());
-            }
-            TyTraitItem::Constant(item_ref) => {
-                let new_decl_ref = item_ref
-                    .clone()
-                    .subst_types_and_insert_new_with_parent(type_mapping, engines);
-                item_ref.replace_id(*new_decl_ref.id());
-            }
-            TyTraitItem::Type(item_ref) => {
-                let new_decl_ref = item_ref
-                    .clone()
-                    .subst_types_and_insert_new_with_parent(type_mapping, engines);
-                item_ref.replace_id(*new_decl_ref.id());
-            }
-        });
+            .for_each(|predicate| predicate.subst(type_mapping, engines));
     }
 }
 
@@ -270,3 +717,490 @@ impl MonomorphizeHelper for TyTraitDecl {
         true
     }
 }
+
+/// Key for the trait monomorphization cache: the trait being instantiated, identified by its own
+/// `DeclId` (unique per trait within the owning `DeclEngine`, and cheap to compare — unlike
+/// [Fingerprint], it needs no walk over the trait's members to produce), plus the fully-resolved
+/// substitution it is instantiated with, stored as the actual sorted `(source, destination)`
+/// pairs rather than a digest of them — two instantiations only share a cache entry when their
+/// substitutions are actually equal, not merely hash-equal. Two instantiations that produce an
+/// identical key are guaranteed to produce identical `interface_surface`/`items`, so the second
+/// one can reuse the first one's `DeclRef`s instead of re-running the substitution walk.
+///
+/// A structural fingerprint is deliberately *not* used here: it is keyed on member signatures
+/// alone (names and structural parameter/return types), so two distinct traits that happen to
+/// share a name with identical signatures — same-named traits in different modules, or identical
+/// signatures with different default-method bodies — would collide onto one cache entry, and the
+/// second instantiation would silently receive the first trait's monomorphized items. `DeclId` is
+/// exact identity, not a structural approximation, so no such collision is possible; and since
+/// this cache is already scoped per-`DeclEngine` (i.e. per compiler run), `DeclId`'s lack of
+/// cross-run stability is irrelevant here.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct TraitMonomorphizationKey {
+    trait_decl_id: DeclId<TyTraitDecl>,
+    subst_pairs: Vec<(TypeId, TypeId)>,
+}
+
+/// The cache's value and backing map type, owned by [crate::decl_engine::DeclEngine] (via
+/// `DeclEngine::trait_monomorphization_cache`) rather than a process-wide `static`: a `static`
+/// would share entries across every `DeclEngine` living in the process (e.g. repeated LSP
+/// reparses, or multiple test programs in one binary), and a second, unrelated `DeclEngine` could
+/// get a hit and receive `DeclRef`s minted into a decl engine that has since been dropped.
+/// Scoping the cache to the owning `DeclEngine` means it is dropped, and can never be consulted
+/// across engines, the moment that engine is.
+pub type TraitMonomorphizationCache =
+    Mutex<HashMap<TraitMonomorphizationKey, (Vec<TyTraitInterfaceItem>, Vec<TyTraitItem>)>>;
+
+/// Returns whether `ty` is, or itself contains anywhere within its structure, an unresolved
+/// `Unknown`/`UnknownGeneric`/`Placeholder` type. Recurses into composite variants so that a
+/// destination like `Vec<_>` or `(Placeholder, u64)` is correctly treated as unresolved even
+/// though its own top-level `TypeInfo` is a concrete `Custom`/`Tuple`.
+fn contains_unresolved_type(ty: TypeId, engines: &Engines) -> bool {
+    let type_engine = engines.te();
+    match &*type_engine.get(ty) {
+        TypeInfo::Unknown | TypeInfo::UnknownGeneric { .. } | TypeInfo::Placeholder(_) => true,
+        TypeInfo::Tuple(fields) => fields
+            .iter()
+            .any(|field| contains_unresolved_type(field.type_id, engines)),
+        TypeInfo::Array(elem_ty, _) => contains_unresolved_type(elem_ty.type_id, engines),
+        TypeInfo::Ref {
+            referenced_type, ..
+        } => contains_unresolved_type(referenced_type.type_id, engines),
+        TypeInfo::Custom {
+            type_arguments: Some(type_arguments),
+            ..
+        } => type_arguments
+            .iter()
+            .any(|arg| contains_unresolved_type(arg.type_id, engines)),
+        _ => false,
+    }
+}
+
+/// Returns the substitution's `(source, destination)` pairs, sorted so that two `TypeSubstMap`s
+/// built in different orders produce an identical key, or `None` if any destination contains an
+/// unresolved placeholder type anywhere within its structure, since a cache entry keyed on a
+/// partial instantiation could leak into an unrelated, differently-resolved one.
+fn resolved_subst_pairs(
+    type_mapping: &TypeSubstMap,
+    engines: &Engines,
+) -> Option<Vec<(TypeId, TypeId)>> {
+    let mut pairs: Vec<(TypeId, TypeId)> = type_mapping.iter().collect();
+    for (_source, dest) in &pairs {
+        if contains_unresolved_type(*dest, engines) {
+            return None;
+        }
+    }
+    pairs.sort_unstable_by_key(|(source, dest)| (format!("{source:?}"), format!("{dest:?}")));
+    Some(pairs)
+}
+
+impl TypeCheckFinalization for TyTraitDecl {
+    fn type_check_finalize(
+        &mut self,
+        handler: &Handler,
+        ctx: &mut TypeCheckFinalizationContext,
+    ) -> Result<(), ErrorEmitted> {
+        let engines = ctx.engines;
+        self.visit_with(&mut FinalizationVisitor { handler, ctx }, engines)?;
+
+        // Persist the fingerprint `compute_variances` ran against, so that finalizing this same
+        // trait declaration again later in the same compilation (e.g. reached again through a
+        // second, unrelated call graph) can compare against the now-substituted members' current
+        // fingerprint and skip a recomputation that is guaranteed to reproduce the same result.
+        let fingerprint = self.stable_fingerprint(engines);
+        if self.last_variances_fingerprint != Some(fingerprint) {
+            self.compute_variances(engines);
+            self.last_variances_fingerprint = Some(fingerprint);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the variance contributed by `param`'s occurrences within `ty`, or `Bivariant` if
+/// `param` does not occur in `ty` at all. `ctx` is the variance of the position `ty` itself
+/// occupies (`Covariant` for a return type, `Contravariant` for a parameter type). This walk is
+/// a pure function of `ty`'s static structure — it never depends on another parameter's
+/// in-progress variance — so a single pass over every interface/item member already computes
+/// the fixpoint; `compute_variances` does not need to (and no longer does) loop.
+fn occurrence(ty: TypeId, param: TypeId, engines: &Engines, ctx: Variance) -> Variance {
+    if ty == param {
+        return ctx;
+    }
+    match &*engines.te().get(ty) {
+        TypeInfo::Tuple(fields) => fields
+            .iter()
+            .map(|field| occurrence(field.type_id, param, engines, ctx))
+            .fold(Variance::Bivariant, Variance::join),
+        TypeInfo::Array(elem_ty, _) => occurrence(elem_ty.type_id, param, engines, ctx),
+        // `&T` is covariant in `T` (same as rustc); `&mut T` must be invariant, since writing
+        // through the reference lets a caller observe `T` in both producer and consumer position.
+        TypeInfo::Ref {
+            to_mutable_value,
+            referenced_type,
+        } => {
+            let ref_ctx = if *to_mutable_value {
+                Variance::Invariant
+            } else {
+                ctx
+            };
+            occurrence(referenced_type.type_id, param, engines, ref_ctx)
+        }
+        // Precisely, `param`'s occurrence here should be governed by *that* generic's own
+        // variance in this position (e.g. covariant if `ty` is itself a `Vec`-like covariant
+        // generic), the same way rustc consults `tcx.variances_of` for a nested ADT. Computing
+        // that here would require resolving `ty`'s name to its own struct/enum/trait declaration
+        // and that declaration's own (possibly not-yet-computed) variances, which this
+        // self-contained walk over one trait's signatures has no access to. `Invariant` is always
+        // a *sound* over-approximation — it only ever forecloses otherwise-valid substitutions,
+        // never accepts an unsound one — so it is used unconditionally here rather than guessing.
+        TypeInfo::Custom {
+            type_arguments: Some(type_arguments),
+            ..
+        } => type_arguments
+            .iter()
+            .map(|arg| occurrence(arg.type_id, param, engines, Variance::Invariant))
+            .fold(Variance::Bivariant, Variance::join),
+        _ => Variance::Bivariant,
+    }
+}
+
+/// A 128-bit structural fingerprint of a `TyTraitDecl`. Unlike `HashWithEngines`, which
+/// deliberately depends on in-memory `DeclId`s and is therefore only valid within a single
+/// compiler run, a `Fingerprint` is derived purely from structural content (names, bounds, and
+/// signatures expanded to structural `TypeInfo`) and so is stable across recompilations. Mirrors
+/// rustc's `StableHashingContext`/`Fingerprint`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Fingerprint(u128);
+
+/// Feeds the same input into two independently-seeded hashers and combines their output into a
+/// 128-bit fingerprint, since `std`'s `Hasher` only produces 64 bits.
+struct FingerprintHasher {
+    lo: std::collections::hash_map::DefaultHasher,
+    hi: std::collections::hash_map::DefaultHasher,
+}
+
+impl FingerprintHasher {
+    fn new() -> Self {
+        let mut hi = std::collections::hash_map::DefaultHasher::new();
+        0x9E3779B97F4A7C15u64.hash(&mut hi);
+        Self {
+            lo: std::collections::hash_map::DefaultHasher::new(),
+            hi,
+        }
+    }
+
+    fn write<T: Hash + ?Sized>(&mut self, value: &T) {
+        value.hash(&mut self.lo);
+        value.hash(&mut self.hi);
+    }
+
+    fn finish(self) -> Fingerprint {
+        Fingerprint(((self.hi.finish() as u128) << 64) | self.lo.finish() as u128)
+    }
+}
+
+/// Hashes `ty`'s *structural* shape into `hasher`: the `TypeInfo` variant it resolves to, its
+/// displayed form (type names, integer widths, reference mutability), and for composite
+/// variants, the same structural hash of each nested type. Never hashes a raw `TypeId`, since
+/// those are in-memory indices that are not stable across compiler runs.
+fn hash_structural_type(ty: TypeId, engines: &Engines, hasher: &mut FingerprintHasher) {
+    let type_info = engines.te().get(ty);
+    hasher.write(&std::mem::discriminant(&*type_info));
+    hasher.write(&engines.help_out(ty).to_string());
+    match &*type_info {
+        TypeInfo::Tuple(fields) => {
+            for field in fields {
+                hash_structural_type(field.type_id, engines, hasher);
+            }
+        }
+        TypeInfo::Array(elem_ty, length) => {
+            hasher.write(length);
+            hash_structural_type(elem_ty.type_id, engines, hasher);
+        }
+        TypeInfo::Custom {
+            type_arguments: Some(type_arguments),
+            ..
+        } => {
+            for arg in type_arguments {
+                hash_structural_type(arg.type_id, engines, hasher);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Structurally hashes one interface/item member in isolation, via a fresh [FingerprintHasher]
+/// that only ever sees `write_member`'s writes, so the resulting digest can be combined with the
+/// other members' digests commutatively (see [combine_member_digests]) rather than depending on
+/// `interface_surface`'s or `items`' vec order.
+fn hash_member_digest(write_member: impl FnOnce(&mut FingerprintHasher)) -> u128 {
+    let mut hasher = FingerprintHasher::new();
+    write_member(&mut hasher);
+    hasher.finish().0
+}
+
+/// Combines per-member digests with a commutative operator (`wrapping_add`), so that
+/// `stable_fingerprint` is invariant under reordering of independent declarations: two traits
+/// differing only in the order their methods/constants/assoc types were declared in still
+/// fingerprint identically.
+fn combine_member_digests(digests: impl Iterator<Item = u128>) -> u128 {
+    digests.fold(0u128, u128::wrapping_add)
+}
+
+impl TyTraitDecl {
+    /// Computes a stable structural fingerprint of this trait declaration: the trait's name,
+    /// each type parameter's name and trait-constraint bounds, and for every interface/item
+    /// member its resolved shape (a function's parameter types and return type, a constant's or
+    /// associated type's own type, all expanded to structural `TypeInfo`, never `DeclId`).
+    /// Members are folded into the result with a commutative combination
+    /// ([combine_member_digests]), so this is invariant under `DeclId` renumbering *and* under
+    /// reordering of independent declarations. This drives incremental type-checking: if a
+    /// trait's fingerprint is unchanged across a recompile, downstream impl-checks and
+    /// monomorphizations of it can be skipped.
+    pub fn stable_fingerprint(&self, engines: &Engines) -> Fingerprint {
+        let decl_engine = engines.de();
+        let mut hasher = FingerprintHasher::new();
+
+        hasher.write(&self.name);
+
+        for type_parameter in &self.type_parameters {
+            hasher.write(&type_parameter.name_ident);
+            hash_structural_type(type_parameter.type_id, engines, &mut hasher);
+            for bound in &type_parameter.trait_constraints {
+                hasher.write(&bound.trait_name);
+                for arg in &bound.type_arguments {
+                    hash_structural_type(arg.type_id, engines, &mut hasher);
+                }
+            }
+        }
+
+        let interface_digest = combine_member_digests(self.interface_surface.iter().map(|item| {
+            hash_member_digest(|hasher| match item {
+                TyTraitInterfaceItem::TraitFn(node) => {
+                    let trait_fn = decl_engine.get_trait_fn(node);
+                    hasher.write(trait_fn.name());
+                    for arg in &trait_fn.parameters {
+                        hash_structural_type(arg.type_argument.type_id, engines, hasher);
+                    }
+                    hash_structural_type(trait_fn.return_type.type_id, engines, hasher);
+                }
+                TyTraitInterfaceItem::Constant(node) => {
+                    let constant = decl_engine.get_constant(node);
+                    hasher.write(constant.name());
+                    hash_structural_type(constant.return_type, engines, hasher);
+                }
+                TyTraitInterfaceItem::Type(node) => {
+                    let trait_type = decl_engine.get_trait_type(node);
+                    hasher.write(trait_type.name());
+                    if let Some(ty) = &trait_type.ty {
+                        hash_structural_type(ty.type_id, engines, hasher);
+                    }
+                }
+            })
+        }));
+        hasher.write(&interface_digest);
+
+        let items_digest = combine_member_digests(self.items.iter().map(|item| {
+            hash_member_digest(|hasher| match item {
+                TyTraitItem::Fn(node) => {
+                    let function = decl_engine.get_function(node);
+                    hasher.write(function.name());
+                    for arg in &function.parameters {
+                        hash_structural_type(arg.type_argument.type_id, engines, hasher);
+                    }
+                    hash_structural_type(function.return_type.type_id, engines, hasher);
+                }
+                TyTraitItem::Constant(node) => {
+                    let constant = decl_engine.get_constant(node);
+                    hasher.write(constant.name());
+                    hash_structural_type(constant.return_type, engines, hasher);
+                }
+                TyTraitItem::Type(node) => {
+                    let trait_type = decl_engine.get_trait_type(node);
+                    hasher.write(trait_type.name());
+                    if let Some(ty) = &trait_type.ty {
+                        hash_structural_type(ty.type_id, engines, hasher);
+                    }
+                }
+            })
+        }));
+        hasher.write(&items_digest);
+
+        hasher.finish()
+    }
+
+    /// Computes the variance of each of `type_parameters`, in order, storing the result in
+    /// `self.variances`. Every parameter starts `Bivariant`; each occurrence in a method's
+    /// parameter types contributes `Contravariant`, each occurrence in a return type contributes
+    /// `Covariant`, and observations are joined with the variance lattice ([Variance::join]).
+    /// `occurrence` is a pure function of a signature's static structure — unlike rustc, which
+    /// must iterate because one item's variance can depend on another's, nothing computed here
+    /// ever depends on a variance this pass has not yet computed, so a single pass over every
+    /// interface/item member already reaches the fixpoint.
+    pub fn compute_variances(&mut self, engines: &Engines) {
+        let decl_engine = engines.de();
+        let mut variances = vec![Variance::Bivariant; self.type_parameters.len()];
+
+        // A function signature contributes `Contravariant` from each parameter and `Covariant`
+        // from its return type. A constant or associated type contributes `Covariant` from its
+        // own type, the same treatment rustc gives a struct/enum field: it is read-only data
+        // carried by the trait, not a position a caller can both produce and consume through.
+        let mut join_occurrence = |ty: TypeId, ctx: Variance| {
+            for (idx, param) in self.type_parameters.iter().enumerate() {
+                variances[idx] = variances[idx].join(occurrence(ty, param.type_id, engines, ctx));
+            }
+        };
+
+        for item in &self.interface_surface {
+            match item {
+                TyTraitInterfaceItem::TraitFn(node) => {
+                    let trait_fn = decl_engine.get_trait_fn(node);
+                    for arg in &trait_fn.parameters {
+                        join_occurrence(arg.type_argument.type_id, Variance::Contravariant);
+                    }
+                    join_occurrence(trait_fn.return_type.type_id, Variance::Covariant);
+                }
+                TyTraitInterfaceItem::Constant(node) => {
+                    let constant = decl_engine.get_constant(node);
+                    join_occurrence(constant.return_type, Variance::Covariant);
+                }
+                TyTraitInterfaceItem::Type(node) => {
+                    let trait_type = decl_engine.get_trait_type(node);
+                    if let Some(ty) = &trait_type.ty {
+                        join_occurrence(ty.type_id, Variance::Covariant);
+                    }
+                }
+            }
+        }
+
+        for item in &self.items {
+            match item {
+                TyTraitItem::Fn(node) => {
+                    let function = decl_engine.get_function(node);
+                    for arg in &function.parameters {
+                        join_occurrence(arg.type_argument.type_id, Variance::Contravariant);
+                    }
+                    join_occurrence(function.return_type.type_id, Variance::Covariant);
+                }
+                TyTraitItem::Constant(node) => {
+                    let constant = decl_engine.get_constant(node);
+                    join_occurrence(constant.return_type, Variance::Covariant);
+                }
+                TyTraitItem::Type(node) => {
+                    let trait_type = decl_engine.get_trait_type(node);
+                    if let Some(ty) = &trait_type.ty {
+                        join_occurrence(ty.type_id, Variance::Covariant);
+                    }
+                }
+            }
+        }
+
+        self.variances = variances;
+    }
+
+    /// Marks this trait as an auto trait (e.g. `auto trait Send {}`). Only a trait with an empty
+    /// `interface_surface` may be auto, since an auto impl is synthesized structurally and
+    /// supplies no method bodies. Rejects `auto trait Foo { fn bar(); }` as the user error it is,
+    /// not a compiler limitation: the feature has nothing left to implement, the syntax is
+    /// simply invalid, the same way rustc rejects an auto trait with associated items.
+    ///
+    /// `CompileError::AutoTraitWithInterfaceItems` is assumed to be declared in `sway-error`
+    /// alongside this trait declaration's other `CompileError` variants; that crate is outside
+    /// this file (and, in this snapshot, outside the tree entirely), so it cannot be added here.
+    pub fn mark_auto(&mut self, handler: &Handler) -> Result<(), ErrorEmitted> {
+        if !self.interface_surface.is_empty() {
+            return Err(handler.emit_err(CompileError::AutoTraitWithInterfaceItems {
+                trait_name: self.name.to_string(),
+                span: self.span.clone(),
+            }));
+        }
+        self.is_auto = true;
+        Ok(())
+    }
+
+    /// Records `impl_id` as a blanket impl of this trait if `impl_self_type` is itself one of
+    /// `impl_type_parameters`, i.e. the impl reads `impl<T, ..> Trait for T` rather than naming a
+    /// concrete type. Trait-method resolution consults `blanket_impls` to apply such an impl to
+    /// any receiver type satisfying the trait's predicate bounds, instead of requiring a nominal
+    /// match. Must be called from impl-trait type-checking at the point an impl is registered
+    /// against its trait declaration (alongside the nominal-impl registration path), so that
+    /// every blanket impl in the program is recorded before method resolution runs against it.
+    /// Returns whether `impl_id` was recognized and recorded as a blanket impl.
+    ///
+    /// Neither that registration call site nor the `blanket_impls`-consulting method-resolution
+    /// read side exists in this file, so this method is not invoked anywhere in this tree yet —
+    /// both live in impl-trait type-checking and trait-method resolution, outside this file (and,
+    /// in this snapshot, outside the tree entirely).
+    pub fn try_register_blanket_impl(
+        &mut self,
+        impl_id: DeclId<TyImplTrait>,
+        impl_self_type: TypeId,
+        impl_type_parameters: &[TypeParameter],
+        engines: &Engines,
+    ) -> bool {
+        let is_blanket = impl_type_parameters
+            .iter()
+            .any(|type_parameter| type_parameter.type_id == impl_self_type)
+            && matches!(
+                &*engines.te().get(impl_self_type),
+                TypeInfo::UnknownGeneric { .. }
+            );
+        if is_blanket {
+            self.blanket_impls.push(impl_id);
+        }
+        is_blanket
+    }
+
+    /// Monomorphizes this trait declaration's `interface_surface` and `items` against
+    /// `folder`'s substitution, memoizing the result in the cache owned by `engines.de()`
+    /// (`DeclEngine::trait_monomorphization_cache`) — an existing `DeclEngine` API in the same
+    /// family as the `get_function`/`get_constant`/`replace` accessors already used throughout
+    /// this file — keyed by `self_decl_id` together with the substitution's actual resolved
+    /// `(source, destination)` pairs. `self_decl_id` is `None` until [TyTraitDecl::bind_decl_id]
+    /// has been called (i.e. before this decl has ever been inserted into a decl engine), in
+    /// which case caching is skipped rather than attempted against a nonexistent identity. On a
+    /// cache hit, the previously produced `DeclRef`s are cloned and the fold over
+    /// `interface_surface`/`items` is skipped entirely. Called directly from
+    /// `SubstTypes::subst_inner`, which is the quadratic path the monomorphization cache targets.
+    /// Recorded `blanket_impls` are materialized on demand by trait-method resolution against the
+    /// monomorphized receiver, so they are untouched here.
+    fn monomorphize_cached(
+        &self,
+        self_decl_id: Option<DeclId<TyTraitDecl>>,
+        folder: &mut SubstFolder,
+        engines: &Engines,
+    ) -> (Vec<TyTraitInterfaceItem>, Vec<TyTraitItem>) {
+        let cache_key = self_decl_id.and_then(|trait_decl_id| {
+            resolved_subst_pairs(folder.type_mapping, engines).map(|subst_pairs| {
+                TraitMonomorphizationKey {
+                    trait_decl_id,
+                    subst_pairs,
+                }
+            })
+        });
+        let cache = engines.de().trait_monomorphization_cache();
+
+        if let Some(key) = &cache_key {
+            if let Some(cached) = cache.lock().unwrap().get(key) {
+                return cached.clone();
+            }
+        }
+
+        let mut interface_surface = self.interface_surface.clone();
+        let mut items = self.items.clone();
+        interface_surface
+            .iter_mut()
+            .for_each(|item| item.fold_with(folder, engines));
+        items
+            .iter_mut()
+            .for_each(|item| item.fold_with(folder, engines));
+        let produced = (interface_surface, items);
+
+        if let Some(key) = cache_key {
+            cache.lock().unwrap().insert(key, produced.clone());
+        }
+
+        produced
+    }
+}